@@ -14,15 +14,9 @@ pub struct CompressedData {
     size: size_t,
 }
 
-#[no_mangle]
-pub extern "C" fn compress_time_series(
-    points: *const TimePoint,
-    len: size_t,
-    out_size: *mut size_t,
-) -> *mut u8 {
-    let points = unsafe { slice::from_raw_parts(points, len) };
-    
-    // Convert points to bytes
+// Serialize a time series to the raw interleaved format and zstd it at the
+// given level.
+fn encode_raw_zstd(points: &[TimePoint], level: i32) -> Vec<u8> {
     let bytes: Vec<u8> = points
         .iter()
         .flat_map(|p| {
@@ -32,53 +26,63 @@ pub extern "C" fn compress_time_series(
             bytes
         })
         .collect();
-    
-    // Compress using zstd
-    let compressed = zstd::encode_all(&bytes[..], 3).unwrap();
-    
-    // Set output size
-    unsafe {
-        *out_size = compressed.len();
-    }
-    
-    // Convert to raw pointer and forget to prevent deallocation
-    let ptr = compressed.as_ptr() as *mut u8;
-    std::mem::forget(compressed);
-    ptr
+    zstd::encode_all(&bytes[..], level).unwrap()
 }
 
-#[no_mangle]
-pub extern "C" fn decompress_time_series(
-    data: *const u8,
-    size: size_t,
-    out_len: *mut size_t,
-) -> *mut TimePoint {
-    let compressed = unsafe { slice::from_raw_parts(data, size) };
-    
-    // Decompress data
-    let decompressed = zstd::decode_all(compressed).unwrap();
-    
-    // Convert bytes back to points
+fn decode_raw_zstd(data: &[u8]) -> Vec<TimePoint> {
+    let decompressed = zstd::decode_all(data).unwrap();
     let mut points = Vec::with_capacity(decompressed.len() / 16);
     let mut i = 0;
     while i < decompressed.len() {
-        let timestamp = i64::from_le_bytes(decompressed[i..i+8].try_into().unwrap());
-        let value = f64::from_le_bytes(decompressed[i+8..i+16].try_into().unwrap());
+        let timestamp = i64::from_le_bytes(decompressed[i..i + 8].try_into().unwrap());
+        let value = f64::from_le_bytes(decompressed[i + 8..i + 16].try_into().unwrap());
         points.push(TimePoint { timestamp, value });
         i += 16;
     }
-    
-    // Set output length
+    points
+}
+
+// Hand a `Vec` to the caller as a raw pointer, recording its length and leaking
+// it so the matching `free_*` entry point can reclaim it.
+fn into_raw_bytes(data: Vec<u8>, out_size: *mut size_t) -> *mut u8 {
+    let data = data.into_boxed_slice();
+    unsafe {
+        *out_size = data.len();
+    }
+    let ptr = data.as_ptr() as *mut u8;
+    std::mem::forget(data);
+    ptr
+}
+
+fn into_raw_points(points: Vec<TimePoint>, out_len: *mut size_t) -> *mut TimePoint {
     unsafe {
         *out_len = points.len();
     }
-    
-    // Convert to raw pointer and forget to prevent deallocation
     let ptr = points.as_ptr() as *mut TimePoint;
     std::mem::forget(points);
     ptr
 }
 
+#[no_mangle]
+pub extern "C" fn compress_time_series(
+    points: *const TimePoint,
+    len: size_t,
+    out_size: *mut size_t,
+) -> *mut u8 {
+    let points = unsafe { slice::from_raw_parts(points, len) };
+    into_raw_bytes(encode_raw_zstd(points, 3), out_size)
+}
+
+#[no_mangle]
+pub extern "C" fn decompress_time_series(
+    data: *const u8,
+    size: size_t,
+    out_len: *mut size_t,
+) -> *mut TimePoint {
+    let compressed = unsafe { slice::from_raw_parts(data, size) };
+    into_raw_points(decode_raw_zstd(compressed), out_len)
+}
+
 #[no_mangle]
 pub extern "C" fn free_compressed_data(data: *mut u8, size: size_t) {
     unsafe {
@@ -93,11 +97,869 @@ pub extern "C" fn free_time_points(points: *mut TimePoint, len: size_t) {
     }
 }
 
-// SIMD-accelerated operations for time series
-#[cfg(target_arch = "x86_64")]
+// Columnar Gorilla-style codec: double-delta timestamps + XOR values.
+//
+// Compared to the raw interleaved format used by `compress_time_series`, this
+// splits the stream into a timestamp column and a value column. Monotonic
+// microsecond timestamps collapse to runs of zero double-deltas, and
+// slowly-varying f64 values collapse to short XOR residuals, so zstd has far
+// less entropy to chew through. The two bit-packed columns are zstd-compressed
+// independently and concatenated behind a small length-prefixed header.
+mod gorilla {
+    /// Bit writer: values are written most-significant bit first, and bits
+    /// fill each byte from its MSB.
+    pub struct BitWriter {
+        bytes: Vec<u8>,
+        cur: u8,
+        nbits: u8,
+    }
+
+    impl Default for BitWriter {
+        fn default() -> Self {
+            BitWriter { bytes: Vec::new(), cur: 0, nbits: 0 }
+        }
+    }
+
+    impl BitWriter {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn write_bit(&mut self, bit: u64) {
+            self.cur = (self.cur << 1) | (bit as u8 & 1);
+            self.nbits += 1;
+            if self.nbits == 8 {
+                self.bytes.push(self.cur);
+                self.cur = 0;
+                self.nbits = 0;
+            }
+        }
+
+        pub fn write_bits(&mut self, val: u64, n: u32) {
+            for i in (0..n).rev() {
+                self.write_bit((val >> i) & 1);
+            }
+        }
+
+        pub fn finish(mut self) -> Vec<u8> {
+            if self.nbits > 0 {
+                self.cur <<= 8 - self.nbits;
+                self.bytes.push(self.cur);
+            }
+            self.bytes
+        }
+    }
+
+    /// Reader counterpart to [`BitWriter`].
+    pub struct BitReader<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+        bit: u8,
+    }
+
+    impl<'a> BitReader<'a> {
+        pub fn new(bytes: &'a [u8]) -> Self {
+            BitReader { bytes, pos: 0, bit: 0 }
+        }
+
+        pub fn read_bit(&mut self) -> u64 {
+            let byte = self.bytes[self.pos];
+            let b = (byte >> (7 - self.bit)) & 1;
+            self.bit += 1;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.pos += 1;
+            }
+            b as u64
+        }
+
+        pub fn read_bits(&mut self, n: u32) -> u64 {
+            let mut v = 0u64;
+            for _ in 0..n {
+                v = (v << 1) | self.read_bit();
+            }
+            v
+        }
+    }
+
+    #[inline]
+    pub(crate) fn zigzag_encode(v: i64) -> u64 {
+        ((v.wrapping_shl(1)) ^ (v >> 63)) as u64
+    }
+
+    #[inline]
+    pub(crate) fn zigzag_decode(u: u64) -> i64 {
+        ((u >> 1) as i64) ^ -((u & 1) as i64)
+    }
+
+    pub(crate) fn write_varint(buf: &mut Vec<u8>, mut v: u64) {
+        loop {
+            let b = (v & 0x7f) as u8;
+            v >>= 7;
+            if v != 0 {
+                buf.push(b | 0x80);
+            } else {
+                buf.push(b);
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn read_varint(buf: &[u8], pos: &mut usize) -> u64 {
+        let mut v = 0u64;
+        let mut shift = 0;
+        loop {
+            let b = buf[*pos];
+            *pos += 1;
+            v |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        v
+    }
+
+    /// Double-delta encode a timestamp column. The first timestamp is stored
+    /// verbatim, the first delta as a zig-zag varint, and every subsequent
+    /// point as the zig-zag varint of `(t[i]-t[i-1]) - (t[i-1]-t[i-2])` — a
+    /// single zero byte for any steady-cadence feed.
+    pub fn encode_timestamps(ts: &[i64]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        if ts.is_empty() {
+            return buf;
+        }
+        buf.extend_from_slice(&ts[0].to_le_bytes());
+        if ts.len() >= 2 {
+            let mut prev_delta = ts[1].wrapping_sub(ts[0]);
+            write_varint(&mut buf, zigzag_encode(prev_delta));
+            for i in 2..ts.len() {
+                let delta = ts[i].wrapping_sub(ts[i - 1]);
+                let dd = delta.wrapping_sub(prev_delta);
+                write_varint(&mut buf, zigzag_encode(dd));
+                prev_delta = delta;
+            }
+        }
+        buf
+    }
+
+    pub fn decode_timestamps(buf: &[u8], count: usize) -> Vec<i64> {
+        let mut out = Vec::with_capacity(count);
+        if count == 0 {
+            return out;
+        }
+        let t0 = i64::from_le_bytes(buf[0..8].try_into().unwrap());
+        out.push(t0);
+        if count >= 2 {
+            let mut pos = 8;
+            let mut prev_delta = zigzag_decode(read_varint(buf, &mut pos));
+            let mut prev = t0.wrapping_add(prev_delta);
+            out.push(prev);
+            for _ in 2..count {
+                let dd = zigzag_decode(read_varint(buf, &mut pos));
+                prev_delta = prev_delta.wrapping_add(dd);
+                prev = prev.wrapping_add(prev_delta);
+                out.push(prev);
+            }
+        }
+        out
+    }
+
+    /// Gorilla XOR encode a value column. The first value is stored as 8 bytes;
+    /// each subsequent value is XORed with its predecessor. A zero XOR is a
+    /// single `0` control bit; otherwise a `1` bit is followed by the leading
+    /// zero-byte count, the meaningful byte count, and the meaningful bytes.
+    pub fn encode_values(vals: &[f64]) -> Vec<u8> {
+        let mut w = BitWriter::new();
+        if vals.is_empty() {
+            return w.finish();
+        }
+        let mut prev = vals[0].to_bits();
+        w.write_bits(prev, 64);
+        for &v in &vals[1..] {
+            let bits = v.to_bits();
+            let xor = bits ^ prev;
+            if xor == 0 {
+                w.write_bit(0);
+            } else {
+                w.write_bit(1);
+                let lead = (xor.leading_zeros() / 8) as u64;
+                let trail = (xor.trailing_zeros() / 8) as u64;
+                let meaningful = 8 - lead - trail;
+                w.write_bits(lead, 3);
+                w.write_bits(meaningful - 1, 3);
+                w.write_bits(xor >> (trail * 8), (meaningful * 8) as u32);
+            }
+            prev = bits;
+        }
+        w.finish()
+    }
+
+    pub fn decode_values(buf: &[u8], count: usize) -> Vec<f64> {
+        let mut out = Vec::with_capacity(count);
+        if count == 0 {
+            return out;
+        }
+        let mut r = BitReader::new(buf);
+        let mut prev = r.read_bits(64);
+        out.push(f64::from_bits(prev));
+        for _ in 1..count {
+            if r.read_bit() == 0 {
+                out.push(f64::from_bits(prev));
+            } else {
+                let lead = r.read_bits(3);
+                let meaningful = r.read_bits(3) + 1;
+                let val = r.read_bits((meaningful * 8) as u32);
+                let trail = 8 - lead - meaningful;
+                prev ^= val << (trail * 8);
+                out.push(f64::from_bits(prev));
+            }
+        }
+        out
+    }
+}
+
+/// Compress a time series with the columnar Gorilla codec.
+///
+/// Wire layout: `[u32 point count][u32 timestamp-blob len][ts blob][value blob]`,
+/// where each blob is an independently zstd-compressed bit-packed column. Pair
+/// with [`decompress_time_series_gorilla`]; the raw format produced by
+/// [`compress_time_series`] is unchanged and remains selectable.
+fn encode_gorilla(points: &[TimePoint]) -> Vec<u8> {
+    let timestamps: Vec<i64> = points.iter().map(|p| p.timestamp).collect();
+    let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+
+    let ts_blob = zstd::encode_all(&gorilla::encode_timestamps(&timestamps)[..], 3).unwrap();
+    let val_blob = zstd::encode_all(&gorilla::encode_values(&values)[..], 3).unwrap();
+
+    let mut out = Vec::with_capacity(8 + ts_blob.len() + val_blob.len());
+    out.extend_from_slice(&(points.len() as u32).to_le_bytes());
+    out.extend_from_slice(&(ts_blob.len() as u32).to_le_bytes());
+    out.extend_from_slice(&ts_blob);
+    out.extend_from_slice(&val_blob);
+    out
+}
+
+fn decode_gorilla(buf: &[u8]) -> Vec<TimePoint> {
+    let count = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+    let ts_len = u32::from_le_bytes(buf[4..8].try_into().unwrap()) as usize;
+
+    let ts_bytes = zstd::decode_all(&buf[8..8 + ts_len]).unwrap();
+    let val_bytes = zstd::decode_all(&buf[8 + ts_len..]).unwrap();
+
+    let timestamps = gorilla::decode_timestamps(&ts_bytes, count);
+    let values = gorilla::decode_values(&val_bytes, count);
+
+    timestamps
+        .into_iter()
+        .zip(values)
+        .map(|(timestamp, value)| TimePoint { timestamp, value })
+        .collect()
+}
+
+#[no_mangle]
+pub extern "C" fn compress_time_series_gorilla(
+    points: *const TimePoint,
+    len: size_t,
+    out_size: *mut size_t,
+) -> *mut u8 {
+    let points = unsafe { slice::from_raw_parts(points, len) };
+    into_raw_bytes(encode_gorilla(points), out_size)
+}
+
+#[no_mangle]
+pub extern "C" fn decompress_time_series_gorilla(
+    data: *const u8,
+    size: size_t,
+    out_len: *mut size_t,
+) -> *mut TimePoint {
+    let buf = unsafe { slice::from_raw_parts(data, size) };
+    into_raw_points(decode_gorilla(buf), out_len)
+}
+
+// Lossy fixed-point linear-prediction codec for the value column.
+//
+// Each value is scaled by `fp` and rounded to the nearest i64, so the maximum
+// absolute error is `0.5 / fp`. The resulting integer series is linear
+// predicted — the predictor for index `i` is `2*x[i-1] - x[i-2]`, the line
+// through the two previous points — and only the zig-zag varint residual is
+// stored (the first two integers are written in full). Smooth price/volume
+// curves collapse into long runs of tiny residuals before zstd. Timestamps are
+// kept exact via the columnar Gorilla encoding.
+mod linear {
+    use super::gorilla;
+
+    /// Scale, round and linear-predict a value column. See the module docs for
+    /// the error bound.
+    pub fn encode_values(vals: &[f64], fp: f64) -> Vec<u8> {
+        let ints: Vec<i64> = vals.iter().map(|&v| (v * fp).round() as i64).collect();
+        let mut buf = Vec::new();
+        if ints.is_empty() {
+            return buf;
+        }
+        gorilla::write_varint(&mut buf, gorilla::zigzag_encode(ints[0]));
+        if ints.len() >= 2 {
+            gorilla::write_varint(&mut buf, gorilla::zigzag_encode(ints[1]));
+        }
+        for i in 2..ints.len() {
+            let predicted = ints[i - 1].wrapping_mul(2).wrapping_sub(ints[i - 2]);
+            let residual = ints[i].wrapping_sub(predicted);
+            gorilla::write_varint(&mut buf, gorilla::zigzag_encode(residual));
+        }
+        buf
+    }
+
+    pub fn decode_values(buf: &[u8], count: usize, fp: f64) -> Vec<f64> {
+        let mut ints: Vec<i64> = Vec::with_capacity(count);
+        if count == 0 {
+            return Vec::new();
+        }
+        let mut pos = 0;
+        ints.push(gorilla::zigzag_decode(gorilla::read_varint(buf, &mut pos)));
+        if count >= 2 {
+            ints.push(gorilla::zigzag_decode(gorilla::read_varint(buf, &mut pos)));
+        }
+        for i in 2..count {
+            let predicted = ints[i - 1].wrapping_mul(2).wrapping_sub(ints[i - 2]);
+            let residual = gorilla::zigzag_decode(gorilla::read_varint(buf, &mut pos));
+            ints.push(predicted.wrapping_add(residual));
+        }
+        ints.iter().map(|&x| x as f64 / fp).collect()
+    }
+
+    /// Pick a fixed-point factor that preserves `significant_digits` digits
+    /// across the series' dynamic range. The factor is a power of ten chosen
+    /// from the largest magnitude present, so the caller effectively asks for
+    /// "N significant decimal digits".
+    pub fn select_fixed_point(vals: &[f64], significant_digits: u32) -> f64 {
+        let max_abs = vals
+            .iter()
+            .map(|v| v.abs())
+            .filter(|v| v.is_finite() && *v > 0.0)
+            .fold(0.0_f64, f64::max);
+        if max_abs == 0.0 {
+            return 10f64.powi(significant_digits.saturating_sub(1) as i32);
+        }
+        let magnitude = max_abs.log10().floor() as i32;
+        let frac_digits = significant_digits as i32 - 1 - magnitude;
+        10f64.powi(frac_digits)
+    }
+}
+
+/// Compress a time series with the lossy fixed-point linear codec.
+///
+/// `fixed_point` is the scaling factor applied to each value before rounding;
+/// the reconstructed values are accurate to within `0.5 / fixed_point`.
+/// Timestamps are preserved exactly. Wire layout:
+/// `[u32 count][f64 fixed_point][u32 ts-blob len][ts blob][value blob]`. Pair
+/// with [`decompress_time_series_linear`].
+fn encode_linear(points: &[TimePoint], fixed_point: f64) -> Vec<u8> {
+    let timestamps: Vec<i64> = points.iter().map(|p| p.timestamp).collect();
+    let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+
+    let ts_blob = zstd::encode_all(&gorilla::encode_timestamps(&timestamps)[..], 3).unwrap();
+    let val_blob = zstd::encode_all(&linear::encode_values(&values, fixed_point)[..], 3).unwrap();
+
+    let mut out = Vec::with_capacity(16 + ts_blob.len() + val_blob.len());
+    out.extend_from_slice(&(points.len() as u32).to_le_bytes());
+    out.extend_from_slice(&fixed_point.to_le_bytes());
+    out.extend_from_slice(&(ts_blob.len() as u32).to_le_bytes());
+    out.extend_from_slice(&ts_blob);
+    out.extend_from_slice(&val_blob);
+    out
+}
+
+fn decode_linear(buf: &[u8]) -> Vec<TimePoint> {
+    let count = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+    let fixed_point = f64::from_le_bytes(buf[4..12].try_into().unwrap());
+    let ts_len = u32::from_le_bytes(buf[12..16].try_into().unwrap()) as usize;
+
+    let ts_bytes = zstd::decode_all(&buf[16..16 + ts_len]).unwrap();
+    let val_bytes = zstd::decode_all(&buf[16 + ts_len..]).unwrap();
+
+    let timestamps = gorilla::decode_timestamps(&ts_bytes, count);
+    let values = linear::decode_values(&val_bytes, count, fixed_point);
+
+    timestamps
+        .into_iter()
+        .zip(values)
+        .map(|(timestamp, value)| TimePoint { timestamp, value })
+        .collect()
+}
+
+#[no_mangle]
+pub extern "C" fn compress_time_series_linear(
+    points: *const TimePoint,
+    len: size_t,
+    fixed_point: c_double,
+    out_size: *mut size_t,
+) -> *mut u8 {
+    let points = unsafe { slice::from_raw_parts(points, len) };
+    into_raw_bytes(encode_linear(points, fixed_point), out_size)
+}
+
+#[no_mangle]
+pub extern "C" fn decompress_time_series_linear(
+    data: *const u8,
+    size: size_t,
+    out_len: *mut size_t,
+) -> *mut TimePoint {
+    let buf = unsafe { slice::from_raw_parts(data, size) };
+    into_raw_points(decode_linear(buf), out_len)
+}
+
+/// Choose a fixed-point factor for [`compress_time_series_linear`] that keeps
+/// `significant_digits` significant decimal digits over the supplied values.
+#[no_mangle]
+pub extern "C" fn select_linear_fixed_point(
+    values: *const f64,
+    len: size_t,
+    significant_digits: c_int,
+) -> c_double {
+    let values = unsafe { slice::from_raw_parts(values, len) };
+    linear::select_fixed_point(values, significant_digits.max(1) as u32)
+}
+
+// Codec benchmarking and auto-selection harness.
+//
+// `benchmark_codecs` runs every candidate encoder over a caller-supplied slice
+// and reports compressed size, ratio, and measured encode/decode throughput, so
+// an embedding application can pick the best ratio-vs-speed tradeoff for a feed.
+// `compress_time_series_auto` applies the winner under a caller-specified
+// constraint.
+
+/// Numeric codec identifiers shared with the FFI layer.
+pub const CODEC_RAW_ZSTD: i32 = 0;
+pub const CODEC_GORILLA: i32 = 1;
+pub const CODEC_LINEAR: i32 = 2;
+
+/// One row of benchmark results. `#[repr(C)]` so the FFI layer can surface an
+/// array of these directly.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CodecBenchmark {
+    pub codec: i32,
+    /// zstd level for `CODEC_RAW_ZSTD`, `-1` otherwise.
+    pub level: i32,
+    /// Fixed-point scaling for `CODEC_LINEAR`, `0.0` otherwise.
+    pub fixed_point: f64,
+    pub uncompressed_size: size_t,
+    pub compressed_size: size_t,
+    pub ratio: f64,
+    pub encode_mb_per_sec: f64,
+    pub decode_mb_per_sec: f64,
+    pub encode_points_per_sec: f64,
+    pub decode_points_per_sec: f64,
+}
+
+/// Which codecs to benchmark and how. The Rust-native counterpart of
+/// [`BenchmarkConfig`].
+#[derive(Clone)]
+pub struct BenchConfig {
+    /// zstd levels to benchmark for the raw codec.
+    pub zstd_levels: Vec<i32>,
+    pub include_gorilla: bool,
+    pub include_linear: bool,
+    /// Fixed-point factor for the linear codec; `None` auto-selects from
+    /// `linear_significant_digits`.
+    pub linear_fixed_point: Option<f64>,
+    pub linear_significant_digits: u32,
+    /// Timing repetitions per codec; treated as at least one.
+    pub iterations: usize,
+}
+
+/// Constraint for [`auto_select_codec`]. A field of `0.0` is "no constraint".
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AutoConstraint {
+    pub min_decode_mb_per_sec: f64,
+    pub min_encode_mb_per_sec: f64,
+}
+
+/// Average wall-clock seconds for one invocation of `f`, over `iterations`
+/// repetitions (at least one).
+fn time_per_iter<F: FnMut()>(iterations: usize, mut f: F) -> f64 {
+    let iterations = iterations.max(1);
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed().as_secs_f64() / iterations as f64
+}
+
+fn throughput(bytes: usize, secs: f64) -> f64 {
+    if secs > 0.0 {
+        (bytes as f64 / (1024.0 * 1024.0)) / secs
+    } else {
+        // Too fast to measure at this iteration count — report it as
+        // unmeasurably fast so throughput constraints treat it as passing,
+        // not as 0 MB/s (which would exclude the fastest codecs).
+        f64::INFINITY
+    }
+}
+
+fn points_throughput(points: usize, secs: f64) -> f64 {
+    if secs > 0.0 {
+        points as f64 / secs
+    } else {
+        f64::INFINITY
+    }
+}
+
+fn bench_one<E, D>(
+    points: &[TimePoint],
+    codec: i32,
+    level: i32,
+    fixed_point: f64,
+    iterations: usize,
+    encode: E,
+    decode: D,
+) -> CodecBenchmark
+where
+    E: Fn(&[TimePoint]) -> Vec<u8>,
+    D: Fn(&[u8]) -> Vec<TimePoint>,
+{
+    let uncompressed_size = points.len() * 16;
+    let encoded = encode(points);
+    let compressed_size = encoded.len();
+
+    let encode_secs = time_per_iter(iterations, || {
+        let _ = encode(points);
+    });
+    let decode_secs = time_per_iter(iterations, || {
+        let _ = decode(&encoded);
+    });
+
+    let ratio = if compressed_size > 0 {
+        uncompressed_size as f64 / compressed_size as f64
+    } else {
+        0.0
+    };
+
+    CodecBenchmark {
+        codec,
+        level,
+        fixed_point,
+        uncompressed_size,
+        compressed_size,
+        ratio,
+        encode_mb_per_sec: throughput(uncompressed_size, encode_secs),
+        decode_mb_per_sec: throughput(uncompressed_size, decode_secs),
+        encode_points_per_sec: points_throughput(points.len(), encode_secs),
+        decode_points_per_sec: points_throughput(points.len(), decode_secs),
+    }
+}
+
+/// Resolve the fixed-point factor the linear codec should use for `points`.
+fn resolve_fixed_point(points: &[TimePoint], config: &BenchConfig) -> f64 {
+    config.linear_fixed_point.unwrap_or_else(|| {
+        let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+        linear::select_fixed_point(&values, config.linear_significant_digits)
+    })
+}
+
+/// Benchmark every configured codec over `points`.
+pub fn benchmark_codecs(points: &[TimePoint], config: &BenchConfig) -> Vec<CodecBenchmark> {
+    let iterations = config.iterations;
+    let mut results = Vec::new();
+
+    for &level in &config.zstd_levels {
+        results.push(bench_one(
+            points,
+            CODEC_RAW_ZSTD,
+            level,
+            0.0,
+            iterations,
+            |p| encode_raw_zstd(p, level),
+            decode_raw_zstd,
+        ));
+    }
+
+    if config.include_gorilla {
+        results.push(bench_one(
+            points,
+            CODEC_GORILLA,
+            -1,
+            0.0,
+            iterations,
+            encode_gorilla,
+            decode_gorilla,
+        ));
+    }
+
+    if config.include_linear {
+        let fp = resolve_fixed_point(points, config);
+        results.push(bench_one(
+            points,
+            CODEC_LINEAR,
+            -1,
+            fp,
+            iterations,
+            |p| encode_linear(p, fp),
+            decode_linear,
+        ));
+    }
+
+    results
+}
+
+/// Re-apply a codec described by a benchmark row.
+fn encode_with(points: &[TimePoint], codec: i32, level: i32, fixed_point: f64) -> Vec<u8> {
+    match codec {
+        CODEC_GORILLA => encode_gorilla(points),
+        CODEC_LINEAR => encode_linear(points, fixed_point),
+        _ => encode_raw_zstd(points, level),
+    }
+}
+
+/// Benchmark the configured codecs, pick the highest-ratio one that satisfies
+/// `constraint`, and return its descriptor together with the encoded blob.
+/// Returns `None` when no codec meets the constraint.
+pub fn auto_select_codec(
+    points: &[TimePoint],
+    config: &BenchConfig,
+    constraint: &AutoConstraint,
+) -> Option<(CodecBenchmark, Vec<u8>)> {
+    let winner = benchmark_codecs(points, config)
+        .into_iter()
+        .filter(|b| b.decode_mb_per_sec >= constraint.min_decode_mb_per_sec)
+        .filter(|b| b.encode_mb_per_sec >= constraint.min_encode_mb_per_sec)
+        .max_by(|a, b| a.ratio.total_cmp(&b.ratio))?;
+
+    let blob = encode_with(points, winner.codec, winner.level, winner.fixed_point);
+    Some((winner, blob))
+}
+
+/// FFI mirror of [`BenchConfig`].
+#[repr(C)]
+pub struct BenchmarkConfig {
+    pub zstd_levels: *const i32,
+    pub zstd_levels_len: size_t,
+    pub include_gorilla: bool,
+    pub include_linear: bool,
+    /// Fixed-point factor for the linear codec; `<= 0.0` auto-selects.
+    pub linear_fixed_point: f64,
+    pub linear_significant_digits: c_int,
+    pub iterations: size_t,
+}
+
+impl BenchmarkConfig {
+    /// # Safety
+    /// `zstd_levels` must point to `zstd_levels_len` valid `i32`s.
+    unsafe fn to_bench_config(&self) -> BenchConfig {
+        let zstd_levels = if self.zstd_levels.is_null() || self.zstd_levels_len == 0 {
+            Vec::new()
+        } else {
+            slice::from_raw_parts(self.zstd_levels, self.zstd_levels_len).to_vec()
+        };
+        BenchConfig {
+            zstd_levels,
+            include_gorilla: self.include_gorilla,
+            include_linear: self.include_linear,
+            linear_fixed_point: if self.linear_fixed_point > 0.0 {
+                Some(self.linear_fixed_point)
+            } else {
+                None
+            },
+            linear_significant_digits: self.linear_significant_digits.max(1) as u32,
+            iterations: self.iterations,
+        }
+    }
+}
+
+/// Benchmark the configured codecs over `points`, returning a heap array of
+/// [`CodecBenchmark`] rows. Free it with [`free_codec_benchmarks`].
+#[no_mangle]
+pub extern "C" fn benchmark_codecs_ffi(
+    points: *const TimePoint,
+    len: size_t,
+    config: *const BenchmarkConfig,
+    out_count: *mut size_t,
+) -> *mut CodecBenchmark {
+    let points = unsafe { slice::from_raw_parts(points, len) };
+    let config = unsafe { (*config).to_bench_config() };
+
+    let results = benchmark_codecs(points, &config).into_boxed_slice();
+
+    unsafe {
+        *out_count = results.len();
+    }
+    let ptr = results.as_ptr() as *mut CodecBenchmark;
+    std::mem::forget(results);
+    ptr
+}
+
+#[no_mangle]
+pub extern "C" fn free_codec_benchmarks(data: *mut CodecBenchmark, count: size_t) {
+    unsafe {
+        let _ = Vec::from_raw_parts(data, count, count);
+    }
+}
+
+/// Select and apply the best codec under `constraint`. On success writes the
+/// chosen descriptor to `out_chosen`, sets `out_size`, and returns the encoded
+/// blob (free with [`free_compressed_data`]). Returns null when no codec meets
+/// the constraint.
+#[no_mangle]
+pub extern "C" fn compress_time_series_auto(
+    points: *const TimePoint,
+    len: size_t,
+    config: *const BenchmarkConfig,
+    constraint: AutoConstraint,
+    out_chosen: *mut CodecBenchmark,
+    out_size: *mut size_t,
+) -> *mut u8 {
+    let points = unsafe { slice::from_raw_parts(points, len) };
+    let config = unsafe { (*config).to_bench_config() };
+
+    match auto_select_codec(points, &config, &constraint) {
+        Some((chosen, blob)) => {
+            unsafe {
+                *out_chosen = chosen;
+            }
+            into_raw_bytes(blob, out_size)
+        }
+        None => {
+            unsafe {
+                *out_size = 0;
+            }
+            std::ptr::null_mut()
+        }
+    }
+}
+
+// SIMD-accelerated operations for time series.
+//
+// The rolling-window kernels are written once and share an architecture-neutral
+// `backend` that provides native vector reductions: an AVX (4-lane) path on
+// x86_64 and a NEON (2-lane) path on aarch64. The horizontal-sum helper is
+// `backend::reduce_add`, so C callers get identical behavior on both targets.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 pub mod simd {
-    use std::arch::x86_64::*;
-    
+    // AVX backend: 4 f64 lanes per vector.
+    #[cfg(target_arch = "x86_64")]
+    mod backend {
+        use std::arch::x86_64::*;
+
+        pub const LANES: usize = 4;
+
+        /// Horizontal sum of a 4-lane vector.
+        #[inline]
+        #[target_feature(enable = "avx")]
+        pub unsafe fn reduce_add(v: __m256d) -> f64 {
+            let sum = _mm256_hadd_pd(v, v);
+            let lo = _mm256_castpd256_pd128(sum);
+            let hi = _mm256_extractf128_pd(sum, 1);
+            let sum = _mm_add_pd(lo, hi);
+            _mm_cvtsd_f64(sum)
+        }
+
+        /// Sum of a slice, vectorized over whole lanes with a scalar tail.
+        #[inline]
+        #[target_feature(enable = "avx")]
+        pub unsafe fn sum(s: &[f64]) -> f64 {
+            let mut acc = 0.0;
+            let mut j = 0;
+            while j + LANES <= s.len() {
+                acc += reduce_add(_mm256_loadu_pd(&s[j]));
+                j += LANES;
+            }
+            for &x in &s[j..] {
+                acc += x;
+            }
+            acc
+        }
+
+        /// Sum of squared deviations from `mean`.
+        #[inline]
+        #[target_feature(enable = "avx")]
+        pub unsafe fn sum_sq_dev(s: &[f64], mean: f64) -> f64 {
+            let mean_v = _mm256_set1_pd(mean);
+            let mut acc = 0.0;
+            let mut j = 0;
+            while j + LANES <= s.len() {
+                let diff = _mm256_sub_pd(_mm256_loadu_pd(&s[j]), mean_v);
+                acc += reduce_add(_mm256_mul_pd(diff, diff));
+                j += LANES;
+            }
+            for &x in &s[j..] {
+                let diff = x - mean;
+                acc += diff * diff;
+            }
+            acc
+        }
+    }
+
+    // NEON backend: 2 f64 lanes per vector.
+    #[cfg(target_arch = "aarch64")]
+    mod backend {
+        use std::arch::aarch64::*;
+
+        pub const LANES: usize = 2;
+
+        /// Horizontal sum of a 2-lane vector.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        pub unsafe fn reduce_add(v: float64x2_t) -> f64 {
+            vaddvq_f64(v)
+        }
+
+        /// Sum of a slice, vectorized over whole lanes with a scalar tail.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        pub unsafe fn sum(s: &[f64]) -> f64 {
+            let mut acc = vdupq_n_f64(0.0);
+            let mut j = 0;
+            while j + LANES <= s.len() {
+                acc = vaddq_f64(acc, vld1q_f64(&s[j]));
+                j += LANES;
+            }
+            let mut total = reduce_add(acc);
+            for &x in &s[j..] {
+                total += x;
+            }
+            total
+        }
+
+        /// Sum of squared deviations from `mean`.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        pub unsafe fn sum_sq_dev(s: &[f64], mean: f64) -> f64 {
+            let mean_v = vdupq_n_f64(mean);
+            let mut acc = vdupq_n_f64(0.0);
+            let mut j = 0;
+            while j + LANES <= s.len() {
+                let diff = vsubq_f64(vld1q_f64(&s[j]), mean_v);
+                acc = vfmaq_f64(acc, diff, diff);
+                j += LANES;
+            }
+            let mut total = reduce_add(acc);
+            for &x in &s[j..] {
+                let diff = x - mean;
+                total += diff * diff;
+            }
+            total
+        }
+    }
+
+    use std::sync::OnceLock;
+
+    /// Whether the running CPU actually supports the vectorized backend. The
+    /// check runs once and is cached; selecting the kernel purely by
+    /// `cfg(target_arch)` would execute AVX on pre-AVX x86 CPUs and fault with
+    /// SIGILL.
+    #[cfg(target_arch = "x86_64")]
+    fn simd_available() -> bool {
+        static AVAILABLE: OnceLock<bool> = OnceLock::new();
+        *AVAILABLE.get_or_init(|| is_x86_feature_detected!("avx"))
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn simd_available() -> bool {
+        static AVAILABLE: OnceLock<bool> = OnceLock::new();
+        *AVAILABLE.get_or_init(|| std::arch::is_aarch64_feature_detected!("neon"))
+    }
+
     #[no_mangle]
     pub extern "C" fn compute_moving_average_simd(
         values: *const f64,
@@ -108,26 +970,29 @@ pub mod simd {
         if window == 0 || len < window {
             return -1;
         }
-        
+
         let values = unsafe { std::slice::from_raw_parts(values, len) };
         let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
-        
-        // Compute first window sum
+
+        // Already a portable running sum — no vector intrinsics, so no dispatch.
+        // Slide a running sum across the series.
         let mut sum = values[..window].iter().sum::<f64>();
-        out[window-1] = sum / window as f64;
-        
-        // Use SIMD for the rest
-        unsafe {
-            for i in window..len {
-                sum += values[i];
-                sum -= values[i - window];
-                out[i] = sum / window as f64;
-            }
+        out[window - 1] = sum / window as f64;
+        for i in window..len {
+            sum += values[i];
+            sum -= values[i - window];
+            out[i] = sum / window as f64;
         }
-        
+
         0
     }
 
+    /// Exponential moving average.
+    ///
+    /// Despite the `_simd` suffix (kept for FFI ABI compatibility with existing
+    /// C consumers), this is a scalar recurrence: EMA is a strict sequential
+    /// dependency — each output feeds the next — and cannot be lane-parallelized,
+    /// so there is no sound AVX/NEON vectorization to route to here.
     #[no_mangle]
     pub extern "C" fn compute_exponential_moving_average_simd(
         values: *const f64,
@@ -135,47 +1000,137 @@ pub mod simd {
         alpha: f64,
         out: *mut f64,
     ) -> i32 {
-        if len == 0 || alpha < 0.0 || alpha > 1.0 {
+        if len == 0 || !(0.0..=1.0).contains(&alpha) {
             return -1;
         }
 
         let values = unsafe { std::slice::from_raw_parts(values, len) };
         let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
 
-        // First value is just copied
+        // EMA is a strict sequential recurrence (each output depends on the
+        // previous output), so it cannot be lane-parallelized — the scalar
+        // recurrence is the only correct formulation on every backend.
+        ema_scalar(values, out, alpha);
+
+        0
+    }
+
+    /// Portable EMA recurrence. EMA = α * current + (1 - α) * prevEMA.
+    fn ema_scalar(values: &[f64], out: &mut [f64], alpha: f64) {
         out[0] = values[0];
+        for i in 1..values.len() {
+            out[i] = alpha * values[i] + (1.0 - alpha) * out[i - 1];
+        }
+    }
 
-        unsafe {
-            let alpha_v = _mm256_set1_pd(alpha);
-            let one_minus_alpha_v = _mm256_set1_pd(1.0 - alpha);
-
-            // Process 4 values at a time using AVX
-            for i in (1..len).step_by(4) {
-                if i + 4 <= len {
-                    let prev_ema = _mm256_loadu_pd(&out[i - 1]);
-                    let curr_values = _mm256_loadu_pd(&values[i]);
-                    
-                    // EMA = α * current + (1 - α) * prevEMA
-                    let ema = _mm256_add_pd(
-                        _mm256_mul_pd(alpha_v, curr_values),
-                        _mm256_mul_pd(one_minus_alpha_v, prev_ema)
-                    );
-                    
-                    _mm256_storeu_pd(&mut out[i], ema);
+    #[no_mangle]
+    pub extern "C" fn compute_standard_deviation_simd(
+        values: *const f64,
+        len: usize,
+        window: usize,
+        out: *mut f64,
+    ) -> i32 {
+        if window == 0 || len < window {
+            return -1;
+        }
+
+        let values = unsafe { std::slice::from_raw_parts(values, len) };
+        let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
+
+        if simd_available() {
+            unsafe { std_dev_vectorized(values, out, window) };
+        } else {
+            std_dev_scalar(values, out, window);
+        }
+
+        0
+    }
+
+    /// Portable rolling standard deviation used when the vectorized backend is
+    /// unavailable.
+    fn std_dev_scalar(values: &[f64], out: &mut [f64], window: usize) {
+        for i in (window - 1)..values.len() {
+            let window_slice = &values[i + 1 - window..=i];
+            let mean = window_slice.iter().sum::<f64>() / window as f64;
+            let var_sum: f64 = window_slice
+                .iter()
+                .map(|&x| {
+                    let diff = x - mean;
+                    diff * diff
+                })
+                .sum();
+            out[i] = (var_sum / window as f64).sqrt();
+        }
+    }
+
+    /// Vectorized rolling standard deviation. The outer window loop exists
+    /// once and is shared between backends; only the enabled target feature
+    /// differs (`avx` on x86_64, `neon` on aarch64), selected via `cfg_attr`.
+    #[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx"))]
+    #[cfg_attr(target_arch = "aarch64", target_feature(enable = "neon"))]
+    unsafe fn std_dev_vectorized(values: &[f64], out: &mut [f64], window: usize) {
+        for i in (window - 1)..values.len() {
+            let window_slice = &values[i + 1 - window..=i];
+            let mean = backend::sum(window_slice) / window as f64;
+            let var_sum = backend::sum_sq_dev(window_slice, mean);
+            out[i] = (var_sum / window as f64).sqrt();
+        }
+    }
+
+    use std::collections::VecDeque;
+
+    /// Sum of a slice, routed through the vectorized backend when available.
+    fn windowed_sum(s: &[f64]) -> f64 {
+        if simd_available() {
+            unsafe { backend::sum(s) }
+        } else {
+            s.iter().sum()
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn compute_rolling_max_simd(
+        values: *const f64,
+        len: usize,
+        window: usize,
+        out: *mut f64,
+    ) -> i32 {
+        if window == 0 || len < window {
+            return -1;
+        }
+
+        let values = unsafe { std::slice::from_raw_parts(values, len) };
+        let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
+
+        // Monotonic deque of indices with strictly decreasing values; the front
+        // is always the window maximum, giving O(n) total work.
+        let mut deque: VecDeque<usize> = VecDeque::new();
+        for i in 0..len {
+            while let Some(&back) = deque.back() {
+                if values[back] <= values[i] {
+                    deque.pop_back();
+                } else {
+                    break;
+                }
+            }
+            deque.push_back(i);
+            while let Some(&front) = deque.front() {
+                if front + window <= i {
+                    deque.pop_front();
                 } else {
-                    // Handle remaining values
-                    for j in i..len {
-                        out[j] = alpha * values[j] + (1.0 - alpha) * out[j - 1];
-                    }
+                    break;
                 }
             }
+            if i >= window - 1 {
+                out[i] = values[*deque.front().unwrap()];
+            }
         }
 
         0
     }
 
     #[no_mangle]
-    pub extern "C" fn compute_standard_deviation_simd(
+    pub extern "C" fn compute_rolling_min_simd(
         values: *const f64,
         len: usize,
         window: usize,
@@ -188,55 +1143,117 @@ pub mod simd {
         let values = unsafe { std::slice::from_raw_parts(values, len) };
         let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
 
-        unsafe {
-            for i in (window-1)..len {
-                let start = i + 1 - window;
-                let window_slice = &values[start..=i];
-
-                // Compute mean using SIMD
-                let mut sum = 0.0;
-                let mut j = 0;
-                while j + 4 <= window {
-                    let v = _mm256_loadu_pd(&window_slice[j]);
-                    sum += _mm256_reduce_add_pd(v);
-                    j += 4;
-                }
-                for k in j..window {
-                    sum += window_slice[k];
-                }
-                let mean = sum / window as f64;
-
-                // Compute variance using SIMD
-                let mean_v = _mm256_set1_pd(mean);
-                let mut var_sum = 0.0;
-                j = 0;
-                while j + 4 <= window {
-                    let v = _mm256_loadu_pd(&window_slice[j]);
-                    let diff = _mm256_sub_pd(v, mean_v);
-                    let sq = _mm256_mul_pd(diff, diff);
-                    var_sum += _mm256_reduce_add_pd(sq);
-                    j += 4;
+        // Monotonic deque with strictly increasing values; the front is the
+        // window minimum.
+        let mut deque: VecDeque<usize> = VecDeque::new();
+        for i in 0..len {
+            while let Some(&back) = deque.back() {
+                if values[back] >= values[i] {
+                    deque.pop_back();
+                } else {
+                    break;
                 }
-                for k in j..window {
-                    let diff = window_slice[k] - mean;
-                    var_sum += diff * diff;
+            }
+            deque.push_back(i);
+            while let Some(&front) = deque.front() {
+                if front + window <= i {
+                    deque.pop_front();
+                } else {
+                    break;
                 }
-
-                out[i] = (var_sum / window as f64).sqrt();
+            }
+            if i >= window - 1 {
+                out[i] = values[*deque.front().unwrap()];
             }
         }
 
         0
     }
 
-    // Helper function to sum 4 doubles in a vector
-    #[inline]
-    unsafe fn _mm256_reduce_add_pd(v: __m256d) -> f64 {
-        let sum = _mm256_hadd_pd(v, v);
-        let lo = _mm256_castpd256_pd128(sum);
-        let hi = _mm256_extractf128_pd(sum, 1);
-        let sum = _mm_add_pd(lo, hi);
-        _mm_cvtsd_f64(sum)
+    #[no_mangle]
+    pub extern "C" fn compute_vwap_simd(
+        prices: *const f64,
+        volumes: *const f64,
+        len: usize,
+        window: usize,
+        out: *mut f64,
+    ) -> i32 {
+        if window == 0 || len < window {
+            return -1;
+        }
+
+        let prices = unsafe { std::slice::from_raw_parts(prices, len) };
+        let volumes = unsafe { std::slice::from_raw_parts(volumes, len) };
+        let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
+
+        // VWAP = Σ(price·volume) / Σ(volume) over the rolling window; both sums
+        // reuse the vectorized horizontal-reduce backend.
+        let pv: Vec<f64> = prices.iter().zip(volumes).map(|(p, v)| p * v).collect();
+        for i in (window - 1)..len {
+            let start = i + 1 - window;
+            let pv_sum = windowed_sum(&pv[start..=i]);
+            let vol_sum = windowed_sum(&volumes[start..=i]);
+            out[i] = if vol_sum != 0.0 { pv_sum / vol_sum } else { 0.0 };
+        }
+
+        0
+    }
+
+    /// Relative strength clamped to the `[0, 100]` RSI range.
+    fn rsi_value(avg_gain: f64, avg_loss: f64) -> f64 {
+        if avg_gain == 0.0 && avg_loss == 0.0 {
+            // A flat window has no directional bias — report neutral RSI.
+            50.0
+        } else if avg_loss == 0.0 {
+            100.0
+        } else {
+            let rs = avg_gain / avg_loss;
+            100.0 - 100.0 / (1.0 + rs)
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn compute_rsi_simd(
+        values: *const f64,
+        len: usize,
+        window: usize,
+        out: *mut f64,
+    ) -> i32 {
+        // Need `window` deltas for the seed average, so at least `window + 1`
+        // points.
+        if window == 0 || len <= window {
+            return -1;
+        }
+
+        let values = unsafe { std::slice::from_raw_parts(values, len) };
+        let out = unsafe { std::slice::from_raw_parts_mut(out, len) };
+
+        // Seed with the simple average of the first `window` gains and losses.
+        let mut gain = 0.0;
+        let mut loss = 0.0;
+        for i in 1..=window {
+            let delta = values[i] - values[i - 1];
+            if delta >= 0.0 {
+                gain += delta;
+            } else {
+                loss -= delta;
+            }
+        }
+        let mut avg_gain = gain / window as f64;
+        let mut avg_loss = loss / window as f64;
+        out[window] = rsi_value(avg_gain, avg_loss);
+
+        // Wilder smoothing for the remaining points.
+        let w = window as f64;
+        for i in (window + 1)..len {
+            let delta = values[i] - values[i - 1];
+            let (g, l) = if delta >= 0.0 { (delta, 0.0) } else { (0.0, -delta) };
+            avg_gain = (avg_gain * (w - 1.0) + g) / w;
+            avg_loss = (avg_loss * (w - 1.0) + l) / w;
+            out[i] = rsi_value(avg_gain, avg_loss);
+        }
+
+        0
     }
 }
 
@@ -283,5 +1300,237 @@ mod tests {
             free_compressed_data(compressed as *mut u8, compressed_size);
             free_time_points(decompressed as *mut TimePoint, decompressed_len);
         }
+
+        #[test]
+        fn test_gorilla_roundtrip(points in prop::collection::vec(
+            (i64::MIN..i64::MAX, f64::MIN..f64::MAX), 0..1000
+        )) {
+            let input: Vec<TimePoint> = points
+                .into_iter()
+                .map(|(t, v)| TimePoint { timestamp: t, value: v })
+                .collect();
+
+            let mut compressed_size = 0;
+            let compressed = compress_time_series_gorilla(
+                input.as_ptr(),
+                input.len(),
+                &mut compressed_size
+            );
+
+            let mut decompressed_len = 0;
+            let decompressed = decompress_time_series_gorilla(
+                compressed,
+                compressed_size,
+                &mut decompressed_len
+            );
+
+            let decompressed_slice = unsafe {
+                slice::from_raw_parts(decompressed, decompressed_len)
+            };
+
+            assert_eq!(input.len(), decompressed_len);
+            for (a, b) in input.iter().zip(decompressed_slice.iter()) {
+                assert_eq!(a.timestamp, b.timestamp);
+                assert_eq!(a.value, b.value);
+            }
+
+            // Clean up
+            free_compressed_data(compressed as *mut u8, compressed_size);
+            free_time_points(decompressed as *mut TimePoint, decompressed_len);
+        }
+
+        #[test]
+        fn test_linear_roundtrip_within_error_bound(points in prop::collection::vec(
+            (i64::MIN..i64::MAX, -1.0e6_f64..1.0e6_f64), 0..1000
+        )) {
+            let fp = 1000.0;
+            let input: Vec<TimePoint> = points
+                .into_iter()
+                .map(|(t, v)| TimePoint { timestamp: t, value: v })
+                .collect();
+
+            let mut compressed_size = 0;
+            let compressed = compress_time_series_linear(
+                input.as_ptr(),
+                input.len(),
+                fp,
+                &mut compressed_size
+            );
+
+            let mut decompressed_len = 0;
+            let decompressed = decompress_time_series_linear(
+                compressed,
+                compressed_size,
+                &mut decompressed_len
+            );
+
+            let decompressed_slice = unsafe {
+                slice::from_raw_parts(decompressed, decompressed_len)
+            };
+
+            assert_eq!(input.len(), decompressed_len);
+            // Timestamps are preserved exactly; values are within 0.5/fp.
+            for (a, b) in input.iter().zip(decompressed_slice.iter()) {
+                assert_eq!(a.timestamp, b.timestamp);
+                assert!((a.value - b.value).abs() <= 0.5 / fp + 1e-9);
+            }
+
+            // Clean up
+            free_compressed_data(compressed as *mut u8, compressed_size);
+            free_time_points(decompressed as *mut TimePoint, decompressed_len);
+        }
+    }
+
+    fn sample_series(n: usize) -> Vec<TimePoint> {
+        (0..n)
+            .map(|i| TimePoint {
+                timestamp: 1_000_000 + i as i64 * 1_000,
+                value: 100.0 + (i as f64 * 0.1).sin(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_benchmark_and_auto_roundtrip() {
+        let points = sample_series(256);
+        let config = BenchConfig {
+            zstd_levels: vec![3],
+            include_gorilla: true,
+            include_linear: true,
+            linear_fixed_point: Some(1000.0),
+            linear_significant_digits: 3,
+            iterations: 1,
+        };
+
+        let rows = benchmark_codecs(&points, &config);
+        assert_eq!(rows.len(), 3);
+        for row in &rows {
+            assert!(row.compressed_size > 0);
+            assert!(row.ratio > 0.0);
+        }
+
+        // A too-fast-to-measure codec must not be excluded by a decode floor.
+        assert!(throughput(points.len() * 16, 0.0) >= 500.0);
+
+        let constraint = AutoConstraint {
+            min_decode_mb_per_sec: 500.0,
+            min_encode_mb_per_sec: 0.0,
+        };
+        let (winner, blob) =
+            auto_select_codec(&points, &config, &constraint).expect("a codec should qualify");
+        assert!(!blob.is_empty());
+        assert!(winner.decode_mb_per_sec >= constraint.min_decode_mb_per_sec);
+    }
+
+    #[test]
+    fn test_rolling_max_matches_naive() {
+        let values: Vec<f64> = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0].to_vec();
+        let window = 3;
+        let mut out = vec![0.0; values.len()];
+        let rc = simd::compute_rolling_max_simd(
+            values.as_ptr(),
+            values.len(),
+            window,
+            out.as_mut_ptr(),
+        );
+        assert_eq!(rc, 0);
+        for i in (window - 1)..values.len() {
+            let naive = values[i + 1 - window..=i]
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert_eq!(out[i], naive);
+        }
+    }
+
+    #[test]
+    fn test_rolling_min_matches_naive() {
+        let values: Vec<f64> = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0].to_vec();
+        let window = 4;
+        let mut out = vec![0.0; values.len()];
+        let rc = simd::compute_rolling_min_simd(
+            values.as_ptr(),
+            values.len(),
+            window,
+            out.as_mut_ptr(),
+        );
+        assert_eq!(rc, 0);
+        for i in (window - 1)..values.len() {
+            let naive = values[i + 1 - window..=i]
+                .iter()
+                .cloned()
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(out[i], naive);
+        }
+    }
+
+    #[test]
+    fn test_vwap_known_values() {
+        let prices = [10.0, 20.0, 30.0];
+        let volumes = [1.0, 2.0, 3.0];
+        let window = 3;
+        let mut out = vec![0.0; prices.len()];
+        let rc = simd::compute_vwap_simd(
+            prices.as_ptr(),
+            volumes.as_ptr(),
+            prices.len(),
+            window,
+            out.as_mut_ptr(),
+        );
+        assert_eq!(rc, 0);
+        // (10·1 + 20·2 + 30·3) / (1 + 2 + 3) = 140 / 6.
+        assert!((out[2] - 140.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rsi_extremes_and_flat() {
+        let window = 3;
+
+        // Strictly rising → only gains → RSI 100.
+        let rising = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut out = vec![0.0; rising.len()];
+        assert_eq!(
+            simd::compute_rsi_simd(rising.as_ptr(), rising.len(), window, out.as_mut_ptr()),
+            0
+        );
+        assert!((out[window] - 100.0).abs() < 1e-9);
+
+        // Strictly falling → only losses → RSI 0.
+        let falling = [5.0, 4.0, 3.0, 2.0, 1.0];
+        let mut out = vec![0.0; falling.len()];
+        assert_eq!(
+            simd::compute_rsi_simd(falling.as_ptr(), falling.len(), window, out.as_mut_ptr()),
+            0
+        );
+        assert!((out[window] - 0.0).abs() < 1e-9);
+
+        // Flat series → no movement → neutral RSI 50.
+        let flat = [7.0, 7.0, 7.0, 7.0, 7.0];
+        let mut out = vec![0.0; flat.len()];
+        assert_eq!(
+            simd::compute_rsi_simd(flat.as_ptr(), flat.len(), window, out.as_mut_ptr()),
+            0
+        );
+        assert!((out[window] - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_std_dev_dispatch_matches_reference() {
+        let values: Vec<f64> = (0..16).map(|i| (i as f64 * 0.3).sin()).collect();
+        let window = 5;
+        let mut out = vec![0.0; values.len()];
+        let rc = simd::compute_standard_deviation_simd(
+            values.as_ptr(),
+            values.len(),
+            window,
+            out.as_mut_ptr(),
+        );
+        assert_eq!(rc, 0);
+        for i in (window - 1)..values.len() {
+            let slice = &values[i + 1 - window..=i];
+            let mean = slice.iter().sum::<f64>() / window as f64;
+            let var = slice.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / window as f64;
+            assert!((out[i] - var.sqrt()).abs() < 1e-9);
+        }
     }
 }